@@ -1,4 +1,4 @@
-use crate::config::Config;
+use crate::config::{BoundaryMode, Config};
 use rand::{thread_rng, Rng};
 
 #[derive(Debug, Clone, Copy)]
@@ -8,9 +8,9 @@ pub struct Vec2 {
 }
 
 impl Vec2 {
-    pub fn zero() -> Self {
-        Self { x: 0.0, y: 0.0 }
-    }
+    pub const ZERO: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+    pub const X: Vec2 = Vec2 { x: 1.0, y: 0.0 };
+    pub const Y: Vec2 = Vec2 { x: 0.0, y: 1.0 };
 
     pub fn random(max_x: f32, max_y: f32) -> Self {
         let mut rng = thread_rng();
@@ -62,6 +62,74 @@ impl Vec2 {
         let dy = self.y - other.y;
         (dx * dx + dy * dy).sqrt()
     }
+
+    /// Distance measured across the shorter toroidal path on each axis, for
+    /// use with `BoundaryMode::Wrap`.
+    pub fn wrapped_distance_to(&self, other: &Vec2, width: f32, height: f32) -> f32 {
+        let dx = wrapped_delta(self.x - other.x, width);
+        let dy = wrapped_delta(self.y - other.y, height);
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    pub fn dot(&self, other: &Vec2) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The 2D cross product / perpendicular dot product. Its sign tells you
+    /// which side of `self` the `other` vector falls on.
+    pub fn perp_dot(&self, other: &Vec2) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn angle(&self) -> f32 {
+        self.y.atan2(self.x)
+    }
+
+    pub fn from_angle(theta: f32) -> Self {
+        Self {
+            x: theta.cos(),
+            y: theta.sin(),
+        }
+    }
+
+    pub fn rotate(&self, theta: f32) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+
+    pub fn lerp(&self, other: &Vec2, t: f32) -> Self {
+        Self {
+            x: self.x + (other.x - self.x) * t,
+            y: self.y + (other.y - self.y) * t,
+        }
+    }
+
+    pub fn clamp_length(&self, min: f32, max: f32) -> Self {
+        let mag = self.magnitude();
+        if mag < min && mag > 0.0 {
+            self.normalize() * min
+        } else if mag > max {
+            self.normalize() * max
+        } else {
+            *self
+        }
+    }
+}
+
+/// Folds a 1D delta into the shortest signed distance on a wrapped axis of
+/// length `span`.
+fn wrapped_delta(delta: f32, span: f32) -> f32 {
+    let half = span * 0.5;
+    if delta > half {
+        delta - span
+    } else if delta < -half {
+        delta + span
+    } else {
+        delta
+    }
 }
 
 impl std::ops::Add for Vec2 {
@@ -115,6 +183,38 @@ impl std::ops::AddAssign for Vec2 {
     }
 }
 
+impl std::ops::SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl std::ops::Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl std::ops::MulAssign<f32> for Vec2 {
+    fn mul_assign(&mut self, scalar: f32) {
+        self.x *= scalar;
+        self.y *= scalar;
+    }
+}
+
+impl std::ops::DivAssign<f32> for Vec2 {
+    fn div_assign(&mut self, scalar: f32) {
+        self.x /= scalar;
+        self.y /= scalar;
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Boid {
     pub position: Vec2,
@@ -128,7 +228,7 @@ impl Boid {
         Self {
             position: Vec2::random(config.width, config.height),
             velocity: Vec2::random_unit() * (config.max_speed * 0.5),
-            acceleration: Vec2::zero(),
+            acceleration: Vec2::ZERO,
             is_leader: false,
         }
     }
@@ -143,18 +243,26 @@ impl Boid {
                 x: config.max_speed * 0.6,
                 y: 0.0,
             },
-            acceleration: Vec2::zero(),
+            acceleration: Vec2::ZERO,
             is_leader: true,
         }
     }
 
     pub fn update(&mut self, config: &Config) {
+        if config.boundary_mode == BoundaryMode::Contain {
+            self.velocity += self.contain_steering(config);
+        }
+
         self.velocity += self.acceleration;
         self.velocity = self.velocity.limit(config.max_speed);
         self.position += self.velocity;
-        self.acceleration = Vec2::zero();
+        self.acceleration = Vec2::ZERO;
 
-        self.bounce_off_boundaries(config);
+        match config.boundary_mode {
+            BoundaryMode::Bounce => self.bounce_off_boundaries(config),
+            BoundaryMode::Wrap => self.wrap_around_boundaries(config),
+            BoundaryMode::Contain => {}
+        }
     }
 
     pub fn apply_force(&mut self, force: Vec2) {
@@ -181,30 +289,50 @@ impl Boid {
         }
     }
 
+    fn wrap_around_boundaries(&mut self, config: &Config) {
+        if self.position.x < 0.0 {
+            self.position.x += config.width;
+        } else if self.position.x > config.width {
+            self.position.x -= config.width;
+        }
+
+        if self.position.y < 0.0 {
+            self.position.y += config.height;
+        } else if self.position.y > config.height {
+            self.position.y -= config.height;
+        }
+    }
+
+    /// A steering force that grows linearly as the boid enters the margin
+    /// around the edge, pushing it back inward without a hard velocity flip.
+    fn contain_steering(&self, config: &Config) -> Vec2 {
+        let margin = config.contain_margin;
+        let mut force = Vec2::ZERO;
+
+        if self.position.x < margin {
+            force.x = (margin - self.position.x) / margin * config.max_force;
+        } else if self.position.x > config.width - margin {
+            force.x = -(self.position.x - (config.width - margin)) / margin * config.max_force;
+        }
+
+        if self.position.y < margin {
+            force.y = (margin - self.position.y) / margin * config.max_force;
+        } else if self.position.y > config.height - margin {
+            force.y = -(self.position.y - (config.height - margin)) / margin * config.max_force;
+        }
+
+        force
+    }
+
+    /// A thin wrapper over `angle()`: buckets the heading into one of 8
+    /// octants and looks up the matching arrow glyph.
     pub fn get_direction_char(&self) -> char {
         if self.is_leader {
             return 'â˜…';
         }
 
-        let angle = self.velocity.y.atan2(self.velocity.x);
-        let pi = std::f32::consts::PI;
-
-        if angle >= -pi / 8.0 && angle < pi / 8.0 {
-            '>'
-        } else if angle >= pi / 8.0 && angle < 3.0 * pi / 8.0 {
-            '\\'
-        } else if angle >= 3.0 * pi / 8.0 && angle < 5.0 * pi / 8.0 {
-            'v'
-        } else if angle >= 5.0 * pi / 8.0 && angle < 7.0 * pi / 8.0 {
-            '/'
-        } else if angle >= 7.0 * pi / 8.0 || angle < -7.0 * pi / 8.0 {
-            '<'
-        } else if angle >= -7.0 * pi / 8.0 && angle < -5.0 * pi / 8.0 {
-            '/'
-        } else if angle >= -5.0 * pi / 8.0 && angle < -3.0 * pi / 8.0 {
-            '^'
-        } else {
-            '\\'
-        }
+        const GLYPHS: [char; 8] = ['>', '\\', 'v', '/', '<', '/', '^', '\\'];
+        let octant = (self.velocity.angle() / (std::f32::consts::PI / 4.0)).round() as i32;
+        GLYPHS[octant.rem_euclid(8) as usize]
     }
 }
@@ -1,5 +1,19 @@
+use crate::boid::Vec2;
+use crate::obstacle::Obstacle;
 use ratatui::layout::Rect;
 
+/// How a boid reacts when it reaches the edge of the simulation area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoundaryMode {
+    /// Reflect velocity off the edge (current behavior).
+    #[default]
+    Bounce,
+    /// Toroidal wrap-around: exiting one edge re-enters on the opposite edge.
+    Wrap,
+    /// A soft steering force that grows as the boid approaches the margin.
+    Contain,
+}
+
 pub struct Config {
     pub width: f32,
     pub height: f32,
@@ -12,6 +26,18 @@ pub struct Config {
     pub separation_weight: f32,
     pub alignment_weight: f32,
     pub cohesion_weight: f32,
+    pub obstacles: Vec<Obstacle>,
+    pub obstacle_lookahead: f32,
+    pub obstacle_margin: f32,
+    pub obstacle_avoidance_weight: f32,
+    pub boundary_mode: BoundaryMode,
+    pub contain_margin: f32,
+    /// How far ahead (in ticks) pursuit/evasion predicts a target's position.
+    pub prediction_factor: f32,
+    /// Leader detection range: inside this, the leader hunts the nearest boid.
+    pub predator_detection_radius: f32,
+    /// Panic range: inside this, followers flee the leader instead of following it.
+    pub predator_flee_radius: f32,
 }
 
 impl Config {
@@ -31,7 +57,29 @@ impl Config {
         // Adjust parameters based on boid density
         let boid_density = num_boids as f32 / area;
         let density_multiplier = (boid_density * 1000.0).max(0.5).min(2.0);
-        
+
+        // A couple of barriers placed relative to the canvas so they're
+        // visible regardless of terminal size
+        let obstacles = vec![
+            Obstacle::Circle {
+                center: Vec2 {
+                    x: canvas_width * 0.5,
+                    y: canvas_height * 0.5,
+                },
+                radius: (canvas_height * 0.15).max(2.0),
+            },
+            Obstacle::Aabb {
+                min: Vec2 {
+                    x: canvas_width * 0.7,
+                    y: canvas_height * 0.1,
+                },
+                max: Vec2 {
+                    x: canvas_width * 0.8,
+                    y: canvas_height * 0.3,
+                },
+            },
+        ];
+
         Self {
             width: canvas_width,
             height: canvas_height,
@@ -44,6 +92,15 @@ impl Config {
             separation_weight: 2.0,
             alignment_weight: 1.2,
             cohesion_weight: 1.0,
+            obstacles,
+            obstacle_lookahead: 4.0,
+            obstacle_margin: 2.0,
+            obstacle_avoidance_weight: 3.0,
+            boundary_mode: BoundaryMode::default(),
+            contain_margin: 5.0,
+            prediction_factor: 8.0,
+            predator_detection_radius: 15.0,
+            predator_flee_radius: 6.0,
         }
     }
 }
@@ -62,6 +119,15 @@ impl Default for Config {
             separation_weight: 2.0,
             alignment_weight: 1.2,
             cohesion_weight: 1.0,
+            obstacles: Vec::new(),
+            obstacle_lookahead: 4.0,
+            obstacle_margin: 2.0,
+            obstacle_avoidance_weight: 3.0,
+            boundary_mode: BoundaryMode::default(),
+            contain_margin: 5.0,
+            prediction_factor: 8.0,
+            predator_detection_radius: 15.0,
+            predator_flee_radius: 6.0,
         }
     }
 }
\ No newline at end of file
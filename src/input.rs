@@ -0,0 +1,80 @@
+use crossterm::event::{self, Event, KeyCode};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::Duration;
+
+/// Keys the simulation reacts to, decoupled from crossterm's `KeyCode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Space,
+    Char(char),
+}
+
+fn translate(code: KeyCode) -> Option<Key> {
+    match code {
+        KeyCode::Up => Some(Key::Up),
+        KeyCode::Down => Some(Key::Down),
+        KeyCode::Left => Some(Key::Left),
+        KeyCode::Right => Some(Key::Right),
+        KeyCode::Char(' ') => Some(Key::Space),
+        KeyCode::Char(c) => Some(Key::Char(c)),
+        _ => None,
+    }
+}
+
+/// Reads stdin key events on a background thread so the render loop never
+/// blocks waiting on input.
+pub struct InputReader {
+    receiver: Receiver<Key>,
+}
+
+impl InputReader {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || loop {
+            match event::poll(Duration::from_millis(50)) {
+                Ok(true) => match event::read() {
+                    Ok(Event::Key(key_event)) => {
+                        if let Some(key) = translate(key_event.code) {
+                            if sender.send(key).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(_) => return,
+                },
+                Ok(false) => {}
+                Err(_) => return,
+            }
+        });
+
+        Self { receiver }
+    }
+
+    /// Drains every pending key event and returns only the most recent one.
+    pub fn poll(&self) -> Option<Key> {
+        let mut latest = None;
+
+        loop {
+            match self.receiver.try_recv() {
+                Ok(key) => latest = Some(key),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => break,
+            }
+        }
+
+        latest
+    }
+}
+
+impl Default for InputReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
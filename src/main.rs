@@ -1,11 +1,15 @@
 mod boid;
 mod config;
+mod input;
+mod obstacle;
 mod simulation;
+mod spatial_grid;
+mod steering;
 mod ui;
 
 use crate::ui::App;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -57,16 +61,8 @@ fn run_app(
         let frame_duration = Duration::from_millis(1000 / target_fps);
         let start_time = Instant::now();
 
-        if event::poll(frame_duration)? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') => return Ok(()),
-                    KeyCode::Char(' ') => app.toggle_pause(),
-                    KeyCode::Char('f') => app.toggle_fps(),
-                    KeyCode::Char('r') => app.reset(),
-                    _ => {}
-                }
-            }
+        if app.handle_input() {
+            return Ok(());
         }
 
         if !app.paused {
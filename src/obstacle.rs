@@ -0,0 +1,148 @@
+use crate::boid::{Boid, Vec2};
+use crate::config::Config;
+
+/// A static barrier boids steer around.
+#[derive(Debug, Clone, Copy)]
+pub enum Obstacle {
+    Circle { center: Vec2, radius: f32 },
+    Aabb { min: Vec2, max: Vec2 },
+}
+
+impl Obstacle {
+    /// The closest point on the obstacle's *surface* to `point`, even when
+    /// `point` is inside the obstacle (needed so the repulsion direction
+    /// stays well-defined at maximum penetration).
+    fn closest_surface_point(&self, point: Vec2) -> Vec2 {
+        match self {
+            Obstacle::Circle { center, radius } => {
+                let offset = point - *center;
+                if offset.magnitude() > f32::EPSILON {
+                    *center + offset.normalize() * *radius
+                } else {
+                    // `point` sits exactly on the center; any direction is
+                    // equally valid, so pick one arbitrarily.
+                    *center + Vec2::X * *radius
+                }
+            }
+            Obstacle::Aabb { min, max } => {
+                let clamped = Vec2 {
+                    x: point.x.clamp(min.x, max.x),
+                    y: point.y.clamp(min.y, max.y),
+                };
+
+                let is_interior =
+                    clamped.x > min.x && clamped.x < max.x && clamped.y > min.y && clamped.y < max.y;
+
+                if !is_interior {
+                    return clamped;
+                }
+
+                // `point` is inside the box; project to the nearest face
+                // instead of returning `point` itself.
+                let dist_left = point.x - min.x;
+                let dist_right = max.x - point.x;
+                let dist_bottom = point.y - min.y;
+                let dist_top = max.y - point.y;
+                let nearest = dist_left.min(dist_right).min(dist_bottom).min(dist_top);
+
+                if nearest == dist_left {
+                    Vec2 { x: min.x, y: point.y }
+                } else if nearest == dist_right {
+                    Vec2 { x: max.x, y: point.y }
+                } else if nearest == dist_bottom {
+                    Vec2 { x: point.x, y: min.y }
+                } else {
+                    Vec2 { x: point.x, y: max.y }
+                }
+            }
+        }
+    }
+
+    /// If `point` is within `margin` of the obstacle, returns the closest
+    /// surface point and how far `point` has penetrated the margin.
+    fn penetration(&self, point: Vec2, margin: f32) -> Option<(Vec2, f32)> {
+        match self {
+            Obstacle::Circle { center, radius } => {
+                let distance = point.distance_to(center);
+                let threshold = radius + margin;
+                if distance < threshold {
+                    Some((self.closest_surface_point(point), threshold - distance))
+                } else {
+                    None
+                }
+            }
+            Obstacle::Aabb { min, max } => {
+                let is_interior = point.x > min.x && point.x < max.x && point.y > min.y && point.y < max.y;
+                let closest = self.closest_surface_point(point);
+                let distance = point.distance_to(&closest);
+
+                if is_interior {
+                    // Penetration is the distance to the nearest face plus
+                    // the full margin, so it's always deepest at the center.
+                    Some((closest, distance + margin))
+                } else if distance < margin {
+                    Some((closest, margin - distance))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub fn glyph(&self) -> char {
+        match self {
+            Obstacle::Circle { .. } => 'O',
+            Obstacle::Aabb { .. } => '#',
+        }
+    }
+}
+
+impl Boid {
+    /// Casts an "ahead" point along the current heading and, if it
+    /// penetrates an obstacle, steers away from the obstacle's surface
+    /// scaled by how deep the penetration is. The summed force is then
+    /// clamped to between 30% of and the full `max_force`.
+    pub fn avoid_obstacles(&self, obstacles: &[Obstacle], config: &Config) -> Vec2 {
+        if obstacles.is_empty() {
+            return Vec2::ZERO;
+        }
+
+        let heading = self.velocity.normalize();
+        let ahead = self.position + heading * config.obstacle_lookahead;
+        let mut steer = Vec2::ZERO;
+
+        for obstacle in obstacles {
+            if let Some((closest, penetration)) = obstacle.penetration(ahead, config.obstacle_margin)
+            {
+                let offset = ahead - closest;
+                let away = if offset.magnitude() > f32::EPSILON {
+                    offset.normalize()
+                } else if self.velocity.magnitude() > f32::EPSILON {
+                    -self.velocity.normalize()
+                } else {
+                    Vec2::X
+                };
+
+                // Slide tangentially around the obstacle's surface, not just
+                // straight back along `away`, so a boid grazing an edge
+                // curves past it instead of stalling head-on. `perp_dot`
+                // picks which side to slide toward; `dot` fades the slide
+                // out once the boid is heading away rather than into it.
+                let tangent = away.rotate(std::f32::consts::FRAC_PI_2);
+                let side = away.perp_dot(&heading).signum();
+                let slide = tangent * side * (-heading.dot(&away)).max(0.0);
+
+                steer += (away + slide) * penetration;
+            }
+        }
+
+        if steer.magnitude() > f32::EPSILON {
+            // Once any obstacle is actually penetrated, guarantee a visible
+            // push rather than one so small it's swamped by the other
+            // steering forces.
+            steer.clamp_length(config.max_force * 0.3, config.max_force)
+        } else {
+            steer
+        }
+    }
+}
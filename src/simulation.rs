@@ -1,5 +1,6 @@
 use crate::boid::{Boid, Vec2};
-use crate::config::Config;
+use crate::config::{BoundaryMode, Config};
+use crate::spatial_grid::SpatialGrid;
 use ratatui::layout::Rect;
 
 #[derive(Debug, Clone, Copy)]
@@ -34,6 +35,8 @@ pub struct Simulation {
     pub boids: Vec<Boid>,
     pub config: Config,
     pub leader: Option<LeaderBird>,
+    grid: SpatialGrid,
+    positions: Vec<Vec2>,
 }
 
 impl Simulation {
@@ -51,11 +54,14 @@ impl Simulation {
         }
 
         let leader = Some(LeaderBird::new(0, &config));
+        let grid = SpatialGrid::new(&config);
 
         Self {
             boids,
             config,
             leader,
+            grid,
+            positions: Vec::new(),
         }
     }
 
@@ -72,11 +78,14 @@ impl Simulation {
         }
 
         let leader = Some(LeaderBird::new(0, &config));
+        let grid = SpatialGrid::new(&config);
 
         Self {
             boids,
             config,
             leader,
+            grid,
+            positions: Vec::new(),
         }
     }
 
@@ -84,13 +93,21 @@ impl Simulation {
         // Update leader logic
         self.update_leader_state();
 
+        self.positions.clear();
+        self.positions.extend(self.boids.iter().map(|b| b.position));
+        self.grid.rebuild(&self.positions, &self.config);
+
         let mut forces = Vec::new();
 
         for i in 0..self.boids.len() {
+            let avoid_obstacles = self.boids[i]
+                .avoid_obstacles(&self.config.obstacles, &self.config)
+                * self.config.obstacle_avoidance_weight;
+
             if self.boids[i].is_leader {
                 // Leader bird uses special patrol force
                 let patrol_force = self.leader_patrol_force(i);
-                forces.push(patrol_force);
+                forces.push(patrol_force + avoid_obstacles);
             } else {
                 // Regular boids use Boids rules + follow leader
                 let separation = self.separation(i);
@@ -101,7 +118,8 @@ impl Simulation {
                 let total_force = separation * self.config.separation_weight
                     + alignment * self.config.alignment_weight
                     + cohesion * self.config.cohesion_weight
-                    + follow_leader * 1.5; // Higher weight for following leader
+                    + follow_leader * 1.5 // Higher weight for following leader
+                    + avoid_obstacles;
 
                 forces.push(total_force);
             }
@@ -113,17 +131,39 @@ impl Simulation {
         }
     }
 
+    /// The grid's neighbor search, using the wrapped variant when boids can
+    /// cross the world edges.
+    fn grid_neighbors(&self, position: Vec2, radius: f32) -> Box<dyn Iterator<Item = usize> + '_> {
+        if self.config.boundary_mode == BoundaryMode::Wrap {
+            Box::new(
+                self.grid
+                    .neighbors_toroidal(position, radius, self.config.width, self.config.height),
+            )
+        } else {
+            Box::new(self.grid.neighbors(position, radius))
+        }
+    }
+
+    fn distance(&self, a: Vec2, b: Vec2) -> f32 {
+        if self.config.boundary_mode == BoundaryMode::Wrap {
+            a.wrapped_distance_to(&b, self.config.width, self.config.height)
+        } else {
+            a.distance_to(&b)
+        }
+    }
+
     fn separation(&self, index: usize) -> Vec2 {
         let current_boid = &self.boids[index];
-        let mut steer = Vec2::zero();
+        let mut steer = Vec2::ZERO;
         let mut count = 0;
 
-        for (i, other) in self.boids.iter().enumerate() {
+        for i in self.grid_neighbors(current_boid.position, self.config.separation_radius) {
             if i == index {
                 continue;
             }
+            let other = &self.boids[i];
 
-            let distance = current_boid.position.distance_to(&other.position);
+            let distance = self.distance(current_boid.position, other.position);
 
             if distance > 0.0 && distance < self.config.separation_radius {
                 let mut diff = current_boid.position - other.position;
@@ -145,15 +185,16 @@ impl Simulation {
 
     fn alignment(&self, index: usize) -> Vec2 {
         let current_boid = &self.boids[index];
-        let mut sum = Vec2::zero();
+        let mut sum = Vec2::ZERO;
         let mut count = 0;
 
-        for (i, other) in self.boids.iter().enumerate() {
+        for i in self.grid_neighbors(current_boid.position, self.config.alignment_radius) {
             if i == index {
                 continue;
             }
+            let other = &self.boids[i];
 
-            let distance = current_boid.position.distance_to(&other.position);
+            let distance = self.distance(current_boid.position, other.position);
 
             if distance > 0.0 && distance < self.config.alignment_radius {
                 sum += other.velocity;
@@ -167,21 +208,22 @@ impl Simulation {
             let steer = sum - current_boid.velocity;
             steer.limit(self.config.max_force)
         } else {
-            Vec2::zero()
+            Vec2::ZERO
         }
     }
 
     fn cohesion(&self, index: usize) -> Vec2 {
         let current_boid = &self.boids[index];
-        let mut sum = Vec2::zero();
+        let mut sum = Vec2::ZERO;
         let mut count = 0;
 
-        for (i, other) in self.boids.iter().enumerate() {
+        for i in self.grid_neighbors(current_boid.position, self.config.cohesion_radius) {
             if i == index {
                 continue;
             }
+            let other = &self.boids[i];
 
-            let distance = current_boid.position.distance_to(&other.position);
+            let distance = self.distance(current_boid.position, other.position);
 
             if distance > 0.0 && distance < self.config.cohesion_radius {
                 sum += other.position;
@@ -193,7 +235,7 @@ impl Simulation {
             sum = sum / count as f32;
             self.seek(current_boid, sum)
         } else {
-            Vec2::zero()
+            Vec2::ZERO
         }
     }
 
@@ -219,6 +261,30 @@ impl Simulation {
         self.leader = Some(LeaderBird::new(0, &self.config));
     }
 
+    /// Applies a steering force directly to the leader boid, letting the
+    /// player push it around with the keyboard.
+    pub fn apply_leader_force(&mut self, force: Vec2) {
+        if let Some(ref leader) = self.leader {
+            if let Some(boid) = self.boids.get_mut(leader.boid_index) {
+                boid.apply_force(force);
+            }
+        }
+    }
+
+    /// Spawns a new follower boid at a random position.
+    pub fn spawn_follower(&mut self) {
+        self.boids.push(Boid::new(&self.config));
+    }
+
+    /// Toggles the `is_leader` flag on the leader boid.
+    pub fn toggle_leader_flag(&mut self) {
+        if let Some(ref leader) = self.leader {
+            if let Some(boid) = self.boids.get_mut(leader.boid_index) {
+                boid.is_leader = !boid.is_leader;
+            }
+        }
+    }
+
     pub fn adjust_boid_count_for_size(&mut self, terminal_size: Rect) {
         let new_config = Config::with_terminal_size(terminal_size);
         let target_count = new_config.num_boids;
@@ -277,12 +343,37 @@ impl Simulation {
         }
     }
 
+    /// The nearest other boid to `from_index` within `radius`, if any. Scans
+    /// every boid rather than going through the grid: there's a single
+    /// caller per tick (the leader hunting prey), so the O(n) cost is
+    /// trivial, and the grid's 3x3-cell window is sized for the much
+    /// smaller flocking radii — it would never reach a `radius` much larger
+    /// than `cell_size`, like `predator_detection_radius`.
+    fn nearest_boid_within(&self, from_index: usize, radius: f32) -> Option<usize> {
+        let position = self.boids[from_index].position;
+        self.boids
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != from_index)
+            .map(|(i, other)| (i, self.distance(position, other.position)))
+            .filter(|&(_, distance)| distance < radius)
+            .min_by(|&(_, a), &(_, b)| a.total_cmp(&b))
+            .map(|(i, _)| i)
+    }
+
     fn leader_patrol_force(&self, index: usize) -> Vec2 {
         if let Some(ref leader) = self.leader {
             if index == leader.boid_index {
                 let boid = &self.boids[index];
 
-                // Calculate sine wave trajectory target position
+                // Predator-style leader: hunt the nearest boid if one is close
+                // enough, otherwise patrol the sine wave trajectory.
+                if let Some(prey_index) =
+                    self.nearest_boid_within(index, self.config.predator_detection_radius)
+                {
+                    return boid.pursue(&self.boids[prey_index], self.config.prediction_factor, &self.config);
+                }
+
                 let center_y = self.config.height * 0.5;
                 let sine_y = center_y + (leader.sine_time.sin() * leader.sine_amplitude);
 
@@ -291,15 +382,10 @@ impl Simulation {
                     y: sine_y.max(0.0).min(self.config.height),
                 };
 
-                let desired = target - boid.position;
-                if desired.magnitude() > 0.0 {
-                    let desired = desired.normalize() * self.config.max_speed;
-                    let steer = desired - boid.velocity;
-                    return steer.limit(self.config.max_force);
-                }
+                return boid.arrive(target, 6.0, &self.config);
             }
         }
-        Vec2::zero()
+        Vec2::ZERO
     }
 
     fn follow_leader_force(&self, index: usize) -> Vec2 {
@@ -307,22 +393,24 @@ impl Simulation {
             let leader_boid = &self.boids[leader.boid_index];
             let current_boid = &self.boids[index];
 
-            // Calculate desired position for following leader (behind the leader)
-            let follow_distance = 8.0;
-            let offset = Vec2 {
-                x: -leader_boid.velocity.normalize().x * follow_distance,
-                y: -leader_boid.velocity.normalize().y * follow_distance,
-            };
+            let distance = self.distance(current_boid.position, leader_boid.position);
+            if distance < self.config.predator_flee_radius {
+                // Too close to the predator: flee instead of following.
+                return current_boid.evade(leader_boid, self.config.prediction_factor, &self.config);
+            }
 
+            // Aim for a spot behind the leader rather than the leader itself
+            let follow_distance = 8.0;
+            let offset = leader_boid.velocity.normalize() * -follow_distance;
             let target_position = leader_boid.position + offset;
-            let desired = target_position - current_boid.position;
 
+            let desired = target_position - current_boid.position;
             if desired.magnitude() > 0.0 {
                 let desired = desired.normalize() * self.config.max_speed * 0.8;
                 let steer = desired - current_boid.velocity;
                 return steer.limit(self.config.max_force * 0.7);
             }
         }
-        Vec2::zero()
+        Vec2::ZERO
     }
 }
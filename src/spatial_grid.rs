@@ -0,0 +1,112 @@
+use crate::boid::Vec2;
+use crate::config::Config;
+use std::collections::HashMap;
+
+type CellCoord = (i32, i32);
+
+/// Buckets boid positions into a uniform grid so neighbor queries only need
+/// to scan nearby cells instead of every boid.
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<CellCoord, Vec<usize>>,
+    positions: Vec<Vec2>,
+}
+
+impl SpatialGrid {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            cell_size: Self::cell_size_for(config),
+            cells: HashMap::new(),
+            positions: Vec::new(),
+        }
+    }
+
+    fn cell_size_for(config: &Config) -> f32 {
+        config
+            .separation_radius
+            .max(config.alignment_radius)
+            .max(config.cohesion_radius)
+            .max(1.0)
+    }
+
+    fn cell_coord(&self, position: Vec2) -> CellCoord {
+        (
+            (position.x / self.cell_size).floor() as i32,
+            (position.y / self.cell_size).floor() as i32,
+        )
+    }
+
+    /// Clears and rebuilds the grid in place from the given positions, avoiding
+    /// per-frame allocation of the bucket map. Recomputes the cell size from
+    /// `config` first, since a resize can change the perception radii it's
+    /// derived from.
+    pub fn rebuild(&mut self, positions: &[Vec2], config: &Config) {
+        let cell_size = Self::cell_size_for(config);
+        if (cell_size - self.cell_size).abs() > f32::EPSILON {
+            // Stale buckets are keyed by the old cell size and would never
+            // be reused again, so drop them instead of just clearing them.
+            self.cell_size = cell_size;
+            self.cells.clear();
+        } else {
+            for bucket in self.cells.values_mut() {
+                bucket.clear();
+            }
+        }
+
+        self.positions.clear();
+        self.positions.extend_from_slice(positions);
+
+        for (index, position) in positions.iter().enumerate() {
+            let coord = self.cell_coord(*position);
+            self.cells.entry(coord).or_default().push(index);
+        }
+    }
+
+    /// Returns the indices of the positions within `radius` of `position`,
+    /// scanning only the 3x3 block of cells around the query point. Positions
+    /// outside that block are not found, so a `radius` larger than the cell
+    /// size is not fully covered.
+    pub fn neighbors(&self, position: Vec2, radius: f32) -> impl Iterator<Item = usize> + '_ {
+        let (cx, cy) = self.cell_coord(position);
+
+        (cx - 1..=cx + 1)
+            .flat_map(move |gx| (cy - 1..=cy + 1).map(move |gy| (gx, gy)))
+            .filter_map(move |coord| self.cells.get(&coord))
+            .flatten()
+            .copied()
+            .filter(move |&index| self.positions[index].distance_to(&position) <= radius)
+    }
+
+    /// Same as `neighbors`, but wraps cell lookups around the toroidal world
+    /// bounds so boids near one edge see neighbors near the opposite edge.
+    pub fn neighbors_toroidal(
+        &self,
+        position: Vec2,
+        radius: f32,
+        width: f32,
+        height: f32,
+    ) -> impl Iterator<Item = usize> + '_ {
+        let cols = (width / self.cell_size).ceil().max(1.0) as i32;
+        let rows = (height / self.cell_size).ceil().max(1.0) as i32;
+        let (cx, cy) = self.cell_coord(position);
+
+        // When the world is narrower than 3 cells on an axis, wrapping the
+        // -1/0/+1 offsets through `rem_euclid` revisits the same cell more
+        // than once; dedup so its boids aren't yielded twice.
+        let mut coords: Vec<CellCoord> = (cx - 1..=cx + 1)
+            .flat_map(|gx| (cy - 1..=cy + 1).map(move |gy| (gx, gy)))
+            .map(|(gx, gy)| (gx.rem_euclid(cols), gy.rem_euclid(rows)))
+            .collect();
+        coords.sort_unstable();
+        coords.dedup();
+
+        coords
+            .into_iter()
+            .filter_map(move |coord| self.cells.get(&coord))
+            .flatten()
+            .copied()
+            .filter(move |&index| {
+                self.positions[index].wrapped_distance_to(&position, width, height) <= radius
+            })
+    }
+}
@@ -0,0 +1,39 @@
+use crate::boid::{Boid, Vec2};
+use crate::config::Config;
+
+/// Classic Reynolds steering behaviors, layered on top of `Boid::apply_force`.
+impl Boid {
+    pub fn seek(&self, target: Vec2, config: &Config) -> Vec2 {
+        let desired = (target - self.position).normalize() * config.max_speed;
+        (desired - self.velocity).limit(config.max_force)
+    }
+
+    /// Like `seek`, but ramps the desired speed down to zero inside
+    /// `slowing_radius` so the boid eases to a stop instead of overshooting.
+    pub fn arrive(&self, target: Vec2, slowing_radius: f32, config: &Config) -> Vec2 {
+        let offset = target - self.position;
+        let distance = offset.magnitude();
+
+        if distance < f32::EPSILON {
+            return Vec2::ZERO;
+        }
+
+        let ramped_speed = config.max_speed * (distance / slowing_radius).min(1.0);
+        let desired = offset.normalize() * ramped_speed;
+        (desired - self.velocity).limit(config.max_force)
+    }
+
+    /// Seeks the predicted future position of `other`, `prediction_factor`
+    /// ticks ahead, instead of its current position.
+    pub fn pursue(&self, other: &Boid, prediction_factor: f32, config: &Config) -> Vec2 {
+        let future_position = other.position + other.velocity * prediction_factor;
+        self.seek(future_position, config)
+    }
+
+    /// Flees the predicted future position of `other`, the mirror of `pursue`.
+    pub fn evade(&self, other: &Boid, prediction_factor: f32, config: &Config) -> Vec2 {
+        let future_position = other.position + other.velocity * prediction_factor;
+        let desired = (self.position - future_position).normalize() * config.max_speed;
+        (desired - self.velocity).limit(config.max_force)
+    }
+}
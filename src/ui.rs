@@ -1,3 +1,6 @@
+use crate::boid::Vec2;
+use crate::input::{InputReader, Key};
+use crate::obstacle::Obstacle;
 use crate::simulation::Simulation;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -9,10 +12,14 @@ use ratatui::{
 };
 use std::time::{Duration, Instant};
 
+/// Magnitude of the force applied to the leader boid per keypress.
+const LEADER_CONTROL_FORCE: f32 = 0.3;
+
 pub struct App {
     pub simulation: Simulation,
     pub paused: bool,
     pub high_fps: bool,
+    input: InputReader,
     last_update: Instant,
     frame_count: u32,
     fps_counter: f32,
@@ -24,12 +31,41 @@ impl App {
             simulation: Simulation::new_with_size(terminal_size),
             paused: false,
             high_fps: false,
+            input: InputReader::new(),
             last_update: Instant::now(),
             frame_count: 0,
             fps_counter: 0.0,
         }
     }
 
+    /// Drains pending keyboard input and applies it. Returns `true` if the
+    /// user requested to quit.
+    pub fn handle_input(&mut self) -> bool {
+        match self.input.poll() {
+            Some(Key::Char('q')) => return true,
+            Some(Key::Space) => self.toggle_pause(),
+            Some(Key::Char('f')) => self.toggle_fps(),
+            Some(Key::Char('r')) => self.reset(),
+            Some(Key::Char('n')) => self.simulation.spawn_follower(),
+            Some(Key::Char('l')) => self.simulation.toggle_leader_flag(),
+            Some(Key::Up) | Some(Key::Char('w')) => {
+                self.simulation.apply_leader_force(Vec2::Y * -LEADER_CONTROL_FORCE)
+            }
+            Some(Key::Down) | Some(Key::Char('s')) => {
+                self.simulation.apply_leader_force(Vec2::Y * LEADER_CONTROL_FORCE)
+            }
+            Some(Key::Left) | Some(Key::Char('a')) => {
+                self.simulation.apply_leader_force(Vec2::X * -LEADER_CONTROL_FORCE)
+            }
+            Some(Key::Right) | Some(Key::Char('d')) => {
+                self.simulation.apply_leader_force(Vec2::X * LEADER_CONTROL_FORCE)
+            }
+            _ => {}
+        }
+
+        false
+    }
+
     pub fn update(&mut self) {
         if !self.paused {
             self.simulation.update();
@@ -85,12 +121,23 @@ impl App {
                 width: (canvas_width / 0.75) as u16, // Reverse calculate terminal width
                 height: canvas_height as u16,
             };
-            
+
             self.simulation.adjust_boid_count_for_size(virtual_terminal_size);
         } else {
-            // Only update boundaries, don't adjust boid count
-            self.simulation.config.width = canvas_width;
-            self.simulation.config.height = canvas_height;
+            // Small changes are eased in rather than snapped to, so the
+            // canvas doesn't visibly jitter every time the terminal is
+            // resized by a pixel or two.
+            let current = Vec2 {
+                x: self.simulation.config.width,
+                y: self.simulation.config.height,
+            };
+            let target = Vec2 {
+                x: canvas_width,
+                y: canvas_height,
+            };
+            let eased = current.lerp(&target, 0.5);
+            self.simulation.config.width = eased.x;
+            self.simulation.config.height = eased.y;
         }
     }
 
@@ -105,6 +152,19 @@ impl App {
             .x_bounds([0.0, self.simulation.config.width.into()])
             .y_bounds([0.0, self.simulation.config.height.into()])
             .paint(|ctx| {
+                for obstacle in &self.simulation.config.obstacles {
+                    for (x, y) in obstacle_points(obstacle) {
+                        ctx.print(
+                            x.into(),
+                            (self.simulation.config.height - y).into(),
+                            Span::styled(
+                                obstacle.glyph().to_string(),
+                                Style::default().fg(Color::DarkGray),
+                            ),
+                        );
+                    }
+                }
+
                 for boid in &self.simulation.boids {
                     // Hide leader bird, don't display
                     if boid.is_leader {
@@ -135,8 +195,8 @@ impl App {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(8),  
-                Constraint::Length(10), 
+                Constraint::Length(11),
+                Constraint::Length(10),
                 Constraint::Min(0),     
             ])
             .split(area);
@@ -161,6 +221,9 @@ impl App {
             ]),
             Line::from(""),
             Line::from(Span::styled("Controls:", Style::default().fg(Color::White).add_modifier(Modifier::BOLD))),
+            Line::from("Arrows/WASD - Steer leader"),
+            Line::from("N - Spawn boid"),
+            Line::from("L - Toggle leader"),
             Line::from("Space - Pause/Resume"),
             Line::from("F - Toggle FPS"),
             Line::from("R - Reset"),
@@ -246,4 +309,33 @@ impl App {
 
         f.render_widget(paragraph, area);
     }
+}
+
+/// Sample points along an obstacle's outline for rendering in the ASCII canvas.
+fn obstacle_points(obstacle: &Obstacle) -> Vec<(f32, f32)> {
+    match *obstacle {
+        Obstacle::Circle { center, radius } => (0..12)
+            .map(|i| {
+                let theta = i as f32 / 12.0 * std::f32::consts::TAU;
+                let point = center + Vec2::from_angle(theta) * radius;
+                (point.x, point.y)
+            })
+            .collect(),
+        Obstacle::Aabb { min, max } => {
+            let mut points = Vec::new();
+            let mut x = min.x;
+            while x <= max.x {
+                points.push((x, min.y));
+                points.push((x, max.y));
+                x += 1.0;
+            }
+            let mut y = min.y;
+            while y <= max.y {
+                points.push((min.x, y));
+                points.push((max.x, y));
+                y += 1.0;
+            }
+            points
+        }
+    }
 }
\ No newline at end of file